@@ -1,8 +1,11 @@
 use ffi_support::rust_string_to_c;
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::ffi::{c_char, CStr, CString};
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
 
 /// Error types that can occur during message processing
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ProcessingError {
     NullPointer,
     InvalidUtf8(std::str::Utf8Error),
@@ -19,6 +22,34 @@ impl ProcessingError {
             ProcessingError::ProcessingFailure(_) => "Error: Failed to process message",
         }
     }
+
+    /// A longer, situation-specific message suitable for `lindos_last_error`.
+    /// Unlike `to_user_message`, this surfaces payloads that are otherwise
+    /// discarded (e.g. the reason a `ProcessingFailure` occurred).
+    fn to_detailed_message(&self) -> String {
+        match self {
+            ProcessingError::NullPointer => self.to_user_message().to_string(),
+            ProcessingError::InvalidUtf8(err) => {
+                format!("{}: {}", self.to_user_message(), err)
+            }
+            ProcessingError::EmptyMessage => self.to_user_message().to_string(),
+            ProcessingError::ProcessingFailure(detail) => {
+                format!("{}: {}", self.to_user_message(), detail)
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Per-thread slot holding the most recent FFI error, for callers using
+    /// the simple `lindos_process_message` path who still want structured
+    /// detail. Thread-local by construction, so no synchronization is needed.
+    static LAST_ERROR: RefCell<Option<ProcessingError>> = const { RefCell::new(None) };
+}
+
+/// Record `error` as the calling thread's last error, or clear it if `None`.
+fn set_last_error(error: Option<ProcessingError>) {
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = error);
 }
 
 /// Result structure for FFI calls that need to return both success/failure and data
@@ -27,6 +58,10 @@ pub struct RustResult {
     pub success: bool,
     pub data: *mut c_char,
     pub error_code: i32,
+    /// The concrete failure reason behind `error_code` (e.g. why a
+    /// `ProcessingFailure` occurred), or null when there is none. Owned by
+    /// this struct; freed by `lindos_result_free`.
+    pub detail: *mut c_char,
 }
 
 impl RustResult {
@@ -35,24 +70,37 @@ impl RustResult {
             success: true,
             data: rust_string_to_c(data),
             error_code: 0,
+            detail: std::ptr::null_mut(),
         }
     }
 
     fn error(error: ProcessingError) -> Self {
         let error_message = error.to_user_message();
+        let error_code = match &error {
+            ProcessingError::NullPointer => 1,
+            ProcessingError::InvalidUtf8(_) => 2,
+            ProcessingError::EmptyMessage => 3,
+            ProcessingError::ProcessingFailure(_) => 4,
+        };
+        let detail = match error {
+            ProcessingError::ProcessingFailure(detail) => rust_string_to_c(detail),
+            _ => std::ptr::null_mut(),
+        };
+
         RustResult {
             success: false,
             data: rust_string_to_c(error_message.to_string()),
-            error_code: match error {
-                ProcessingError::NullPointer => 1,
-                ProcessingError::InvalidUtf8(_) => 2,
-                ProcessingError::EmptyMessage => 3,
-                ProcessingError::ProcessingFailure(_) => 4,
-            },
+            error_code,
+            detail,
         }
     }
 }
 
+/// Longest message `generate_reply` will process. Surfaced to callers via
+/// `RustResult::detail` on `lindos_process_message_safe` rather than just a
+/// bare "Message too long" error code.
+const MAX_MESSAGE_LENGTH: usize = 1000;
+
 /// Internal function to generate replies with error handling
 fn generate_reply(input: &str) -> Result<String, ProcessingError> {
     if input.trim().is_empty() {
@@ -60,10 +108,12 @@ fn generate_reply(input: &str) -> Result<String, ProcessingError> {
     }
 
     // Simulate potential processing errors for demonstration
-    if input.len() > 1000 {
-        return Err(ProcessingError::ProcessingFailure(
-            "Message too long".to_string(),
-        ));
+    if input.len() > MAX_MESSAGE_LENGTH {
+        return Err(ProcessingError::ProcessingFailure(format!(
+            "Message too long: {} bytes exceeds the {}-byte limit",
+            input.len(),
+            MAX_MESSAGE_LENGTH
+        )));
     }
 
     let mut output = String::from("You said: ");
@@ -71,16 +121,32 @@ fn generate_reply(input: &str) -> Result<String, ProcessingError> {
     Ok(output)
 }
 
-/// Safe wrapper for string conversion from C
-fn safe_str_from_ptr(ptr: *const c_char) -> Result<&'static str, ProcessingError> {
+/// Controls whether `safe_str_from_ptr` rejects invalid UTF-8 (the default)
+/// or lossily substitutes U+FFFD for bad byte sequences. See
+/// `lindos_set_lossy_utf8`.
+static LOSSY_UTF8: AtomicBool = AtomicBool::new(false);
+
+/// Safe wrapper for string conversion from C. Borrows from the input when
+/// the bytes are already valid UTF-8; allocates only when lossy mode has to
+/// substitute replacement characters.
+fn safe_str_from_ptr(ptr: *const c_char) -> Result<Cow<'static, str>, ProcessingError> {
     if ptr.is_null() {
         return Err(ProcessingError::NullPointer);
     }
 
     unsafe {
-        CStr::from_ptr(ptr)
-            .to_str()
-            .map_err(ProcessingError::InvalidUtf8)
+        let bytes = CStr::from_ptr(ptr).to_bytes();
+
+        if LOSSY_UTF8.load(Ordering::SeqCst) {
+            Ok(match String::from_utf8_lossy(bytes) {
+                Cow::Borrowed(s) => Cow::Borrowed(s),
+                Cow::Owned(s) => Cow::Owned(s),
+            })
+        } else {
+            std::str::from_utf8(bytes)
+                .map(Cow::Borrowed)
+                .map_err(ProcessingError::InvalidUtf8)
+        }
     }
 }
 
@@ -94,68 +160,153 @@ fn safe_str_from_ptr(ptr: *const c_char) -> Result<&'static str, ProcessingError
 #[no_mangle]
 pub extern "C" fn lindos_process_message(message: *const c_char) -> *mut c_char {
     let result = match safe_str_from_ptr(message) {
-        Ok(input) => match generate_reply(input) {
-            Ok(reply) => reply,
+        Ok(input) => match generate_reply(&input) {
+            Ok(reply) => {
+                set_last_error(None);
+                reply
+            }
             Err(error) => {
-                eprintln!("Processing error: {:?}", error);
-                error.to_user_message().to_string()
+                log(LOG_LEVEL_ERROR, &format!("Processing error: {:?}", error));
+                let message = error.to_user_message().to_string();
+                set_last_error(Some(error));
+                message
             }
         },
         Err(error) => {
-            eprintln!("Input conversion error: {:?}", error);
-            error.to_user_message().to_string()
+            log(
+                LOG_LEVEL_ERROR,
+                &format!("Input conversion error: {:?}", error),
+            );
+            let message = error.to_user_message().to_string();
+            set_last_error(Some(error));
+            message
         }
     };
 
     rust_string_to_c(result)
 }
 
-/// Enhanced version that returns structured results with error information.
-/// Callers must free both data and error_message with `lindos_string_free`.
-///
-/// # Safety
-/// This function is safe to call from C/Swift as long as:
-/// - The message pointer is either null or points to a valid null-terminated C string
-/// - The returned RustResult's data pointer is freed exactly once using `lindos_string_free`
-#[no_mangle]
-pub extern "C" fn lindos_process_message_safe(message: *const c_char) -> RustResult {
+/// Shared implementation behind `lindos_process_message_safe` and
+/// `lindos_process_batch`: validate, process, and record the last-error slot
+/// for a single message.
+fn process_message_safe_inner(message: *const c_char) -> RustResult {
     let input_result = safe_str_from_ptr(message);
 
     match input_result {
-        Ok(input) => match generate_reply(input) {
+        Ok(input) => match generate_reply(&input) {
             Ok(reply) => {
-                println!("Successfully processed message: {} chars", input.len());
+                log(
+                    LOG_LEVEL_INFO,
+                    &format!("Successfully processed message: {} chars", input.len()),
+                );
+                set_last_error(None);
                 RustResult::success(reply)
             }
             Err(error) => {
-                eprintln!("Processing failed: {:?}", error);
+                log(LOG_LEVEL_ERROR, &format!("Processing failed: {:?}", error));
+                set_last_error(Some(error.clone()));
                 RustResult::error(error)
             }
         },
         Err(error) => {
-            eprintln!("Input validation failed: {:?}", error);
+            log(
+                LOG_LEVEL_ERROR,
+                &format!("Input validation failed: {:?}", error),
+            );
+            set_last_error(Some(error.clone()));
             RustResult::error(error)
         }
     }
 }
 
+/// Enhanced version that returns structured results with error information.
+/// Callers must free the result with `lindos_result_free`, which releases
+/// both `data` and `detail`.
+///
+/// # Safety
+/// This function is safe to call from C/Swift as long as:
+/// - The message pointer is either null or points to a valid null-terminated C string
+/// - The returned RustResult is freed exactly once using `lindos_result_free`
+#[no_mangle]
+pub extern "C" fn lindos_process_message_safe(message: *const c_char) -> RustResult {
+    process_message_safe_inner(message)
+}
+
+/// Process `count` messages in a single call, amortizing the FFI boundary
+/// crossing and allocation round-trip that `lindos_process_message_safe`
+/// pays per call. Returns a heap-allocated array of `count` `RustResult`s in
+/// the same order as `messages`, to be freed with `lindos_batch_free`.
+///
+/// # Safety
+/// This function is safe to call from C/Swift as long as:
+/// - `messages` points to an array of exactly `count` valid C string pointers
+///   (each either null or null-terminated), or `count` is 0
+/// - The returned pointer is freed exactly once via `lindos_batch_free`,
+///   passing the same `count` used here
+#[no_mangle]
+pub unsafe extern "C" fn lindos_process_batch(
+    messages: *const *const c_char,
+    count: usize,
+) -> *mut RustResult {
+    let mut results = Vec::with_capacity(count);
+    for i in 0..count {
+        let message = *messages.add(i);
+        results.push(process_message_safe_inner(message));
+    }
+
+    // `into_boxed_slice` shrinks the allocation to exactly `count` elements,
+    // so `lindos_batch_free` can reconstruct it with `Vec::from_raw_parts`
+    // using `count` for both length and capacity.
+    Box::into_raw(results.into_boxed_slice()) as *mut RustResult
+}
+
+/// Free an array of `RustResult`s returned by `lindos_process_batch`.
+///
+/// # Safety
+/// This function is safe to call as long as:
+/// - `ptr` was returned by `lindos_process_batch`
+/// - `count` is exactly the `count` passed to that call
+/// - The array is freed exactly once
+#[no_mangle]
+pub unsafe extern "C" fn lindos_batch_free(ptr: *mut RustResult, count: usize) {
+    if ptr.is_null() {
+        return;
+    }
+
+    let results = Vec::from_raw_parts(ptr, count, count);
+    for result in results {
+        lindos_result_free(result);
+    }
+}
+
 /// Check if a message would be valid without processing it
 #[no_mangle]
 pub extern "C" fn lindos_validate_message(message: *const c_char) -> i32 {
     match safe_str_from_ptr(message) {
         Ok(input) => {
-            if input.len() > 1000 {
+            if input.len() > MAX_MESSAGE_LENGTH {
+                let error = ProcessingError::ProcessingFailure(format!(
+                    "Message too long: {} bytes exceeds the {}-byte limit",
+                    input.len(),
+                    MAX_MESSAGE_LENGTH
+                ));
+                set_last_error(Some(error));
                 4 // ProcessingFailure error code
             } else {
+                set_last_error(None);
                 0 // Success
             }
         }
-        Err(error) => match error {
-            ProcessingError::NullPointer => 1,
-            ProcessingError::InvalidUtf8(_) => 2,
-            ProcessingError::EmptyMessage => 3,
-            ProcessingError::ProcessingFailure(_) => 4,
-        },
+        Err(error) => {
+            let code = match error {
+                ProcessingError::NullPointer => 1,
+                ProcessingError::InvalidUtf8(_) => 2,
+                ProcessingError::EmptyMessage => 3,
+                ProcessingError::ProcessingFailure(_) => 4,
+            };
+            set_last_error(Some(error));
+            code
+        }
     }
 }
 
@@ -173,6 +324,56 @@ pub extern "C" fn lindos_error_message(error_code: i32) -> *mut c_char {
     rust_string_to_c(message.to_string())
 }
 
+/// Returns the longest message `lindos_process_message`/`_safe` will accept,
+/// in bytes, so a host UI can display the limit without parsing it out of
+/// `RustResult::detail`.
+#[no_mangle]
+pub extern "C" fn lindos_max_message_length() -> usize {
+    MAX_MESSAGE_LENGTH
+}
+
+/// Toggle how inbound messages are decoded: strict (the default) rejects
+/// invalid UTF-8 with error code 2, while lossy substitutes U+FFFD for bad
+/// byte sequences and processes the message anyway.
+#[no_mangle]
+pub extern "C" fn lindos_set_lossy_utf8(enabled: bool) {
+    LOSSY_UTF8.store(enabled, Ordering::SeqCst);
+}
+
+/// Returns the error code of the calling thread's last recorded error, or 0
+/// if the most recent message-processing or validation call on this thread
+/// succeeded (or none has run yet). Other entry points leave the slot as it
+/// is. Mirrors the codes returned elsewhere in this module.
+#[no_mangle]
+pub extern "C" fn lindos_last_error_code() -> i32 {
+    LAST_ERROR.with(|slot| match &*slot.borrow() {
+        None => 0,
+        Some(ProcessingError::NullPointer) => 1,
+        Some(ProcessingError::InvalidUtf8(_)) => 2,
+        Some(ProcessingError::EmptyMessage) => 3,
+        Some(ProcessingError::ProcessingFailure(_)) => 4,
+    })
+}
+
+/// Returns a freshly allocated, detailed message for the calling thread's
+/// last recorded error (including detail discarded by the plain error code,
+/// such as the reason a `ProcessingFailure` occurred), or null if the most
+/// recent message-processing or validation call on this thread succeeded or
+/// none has run yet. Callers must free the result with `lindos_string_free`.
+#[no_mangle]
+pub extern "C" fn lindos_last_error() -> *mut c_char {
+    LAST_ERROR.with(|slot| match &*slot.borrow() {
+        Some(error) => rust_string_to_c(error.to_detailed_message()),
+        None => std::ptr::null_mut(),
+    })
+}
+
+/// Clears the calling thread's last recorded error.
+#[no_mangle]
+pub extern "C" fn lindos_clear_last_error() {
+    set_last_error(None);
+}
+
 /// Frees strings that originated from this library.
 ///
 /// # Safety
@@ -183,7 +384,7 @@ pub extern "C" fn lindos_error_message(error_code: i32) -> *mut c_char {
 #[no_mangle]
 pub unsafe extern "C" fn lindos_string_free(ptr: *mut c_char) {
     if ptr.is_null() {
-        eprintln!("Warning: Attempted to free null pointer");
+        log(LOG_LEVEL_WARN, "Warning: Attempted to free null pointer");
         return;
     }
 
@@ -202,36 +403,103 @@ pub unsafe extern "C" fn lindos_result_free(result: RustResult) {
     if !result.data.is_null() {
         lindos_string_free(result.data);
     }
+    if !result.detail.is_null() {
+        lindos_string_free(result.detail);
+    }
 }
 
-/// Enable or disable debug logging
-static mut DEBUG_ENABLED: bool = false;
+/// Severity levels passed to the registered log callback.
+pub const LOG_LEVEL_DEBUG: i32 = 0;
+pub const LOG_LEVEL_INFO: i32 = 1;
+pub const LOG_LEVEL_WARN: i32 = 2;
+pub const LOG_LEVEL_ERROR: i32 = 3;
 
+/// Signature the host platform registers via `lindos_set_log_callback`.
+pub type LogCallback = extern "C" fn(level: i32, msg: *const c_char);
+
+/// Host-registered log sink, stored behind an `AtomicPtr` so it can be set
+/// from any thread without a lock. Null means "no sink registered".
+static LOG_CALLBACK: AtomicPtr<()> = AtomicPtr::new(std::ptr::null_mut());
+
+/// Level filter toggled by `lindos_set_debug`: when disabled, `LOG_LEVEL_DEBUG`
+/// traces are dropped before reaching the callback (or stderr); every other
+/// level is always forwarded.
+static DEBUG_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Register (or clear, with `None`) the host's log sink. Internal diagnostics
+/// that used to go straight to stdout/stderr are routed here instead so a
+/// Swift app can funnel them into its own logging framework.
+///
+/// The callback type is written out here (rather than via the `LogCallback`
+/// alias) so cbindgen emits a concrete `void (*)(int32_t, const char *)`
+/// function pointer in the generated header instead of an opaque,
+/// uninstantiable `Option<LogCallback>` wrapper struct.
+///
+/// # Safety
+/// This function is safe to call as long as `cb`, if present, is a valid
+/// function pointer for the lifetime of the program (or until replaced by
+/// another call to this function).
+#[no_mangle]
+pub unsafe extern "C" fn lindos_set_log_callback(
+    cb: Option<extern "C" fn(level: i32, msg: *const c_char)>,
+) {
+    let ptr = match cb {
+        Some(f) => f as usize as *mut (),
+        None => std::ptr::null_mut(),
+    };
+    LOG_CALLBACK.store(ptr, Ordering::SeqCst);
+}
+
+/// Enable or disable debug-level log traces.
 #[no_mangle]
 pub extern "C" fn lindos_set_debug(enabled: bool) {
-    unsafe {
-        DEBUG_ENABLED = enabled;
-    }
-    println!(
-        "Debug logging {}",
-        if enabled { "enabled" } else { "disabled" }
+    DEBUG_ENABLED.store(enabled, Ordering::SeqCst);
+    log(
+        LOG_LEVEL_INFO,
+        &format!(
+            "Debug logging {}",
+            if enabled { "enabled" } else { "disabled" }
+        ),
     );
 }
 
-/// Internal logging function
+/// Routes an internal diagnostic to the registered log callback, falling
+/// back to stderr when no callback is registered. `LOG_LEVEL_DEBUG` traces
+/// are dropped unless debug logging has been enabled via `lindos_set_debug`.
+fn log(level: i32, message: &str) {
+    if level == LOG_LEVEL_DEBUG && !DEBUG_ENABLED.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let ptr = LOG_CALLBACK.load(Ordering::SeqCst);
+    if ptr.is_null() {
+        eprintln!("{}", message);
+        return;
+    }
+
+    // Safety: `ptr` was produced from a `LogCallback` in `lindos_set_log_callback`.
+    let callback: LogCallback = unsafe { std::mem::transmute(ptr) };
+    if let Ok(c_message) = CString::new(message) {
+        callback(level, c_message.as_ptr());
+    }
+}
+
+/// Internal trace-level logging helper.
 #[allow(dead_code)]
 fn debug_log(message: &str) {
-    unsafe {
-        if DEBUG_ENABLED {
-            println!("[LINDOS DEBUG] {}", message);
-        }
-    }
+    log(LOG_LEVEL_DEBUG, &format!("[LINDOS DEBUG] {}", message));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::ffi::CString;
+    use std::sync::Mutex;
+
+    /// Serializes tests that mutate process-global state (`LOG_CALLBACK`,
+    /// `LOSSY_UTF8`) so they don't observe each other's FFI calls mid-test;
+    /// `cargo test` runs tests concurrently by default.
+    static GLOBAL_STATE_LOCK: Mutex<()> = Mutex::new(());
 
     // Helper functions to make unsafe FFI calls safer and more convenient in tests
     fn free_string(ptr: *mut c_char) {
@@ -358,6 +626,28 @@ mod tests {
         // This test mainly ensures the function doesn't crash
     }
 
+    static TEST_LOG_LEVEL: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new(-1);
+
+    extern "C" fn test_log_callback(level: i32, _msg: *const c_char) {
+        TEST_LOG_LEVEL.store(level, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_log_callback_receives_messages() {
+        let _guard = GLOBAL_STATE_LOCK.lock().unwrap();
+
+        TEST_LOG_LEVEL.store(-1, Ordering::SeqCst);
+        unsafe { lindos_set_log_callback(Some(test_log_callback)) };
+
+        let test_str = CString::new("hi").unwrap();
+        let result = lindos_process_message_safe(test_str.as_ptr());
+        free_result(result);
+
+        assert_eq!(TEST_LOG_LEVEL.load(Ordering::SeqCst), LOG_LEVEL_INFO);
+
+        unsafe { lindos_set_log_callback(None) };
+    }
+
     #[test]
     fn test_edge_cases() {
         // Test empty string (not null, but empty)
@@ -407,6 +697,30 @@ mod tests {
         free_result(result);
     }
 
+    #[test]
+    fn test_lossy_utf8_mode() {
+        let _guard = GLOBAL_STATE_LOCK.lock().unwrap();
+
+        // Invalid UTF-8 is rejected by default (strict mode).
+        let invalid_bytes = vec![b'h', b'i', 0xff, 0xfe, 0];
+        let invalid_cstr = unsafe { CStr::from_bytes_with_nul_unchecked(&invalid_bytes) };
+        let result = lindos_process_message_safe(invalid_cstr.as_ptr());
+        assert!(!result.success);
+        assert_eq!(result.error_code, 2);
+        free_result(result);
+
+        // In lossy mode the same bytes are processed with replacement characters.
+        lindos_set_lossy_utf8(true);
+        let result = lindos_process_message_safe(invalid_cstr.as_ptr());
+        assert!(result.success);
+        let response = unsafe { CStr::from_ptr(result.data).to_str().unwrap() };
+        assert!(response.starts_with("You said: hi"));
+        assert!(response.contains('\u{FFFD}'));
+        free_result(result);
+
+        lindos_set_lossy_utf8(false);
+    }
+
     #[test]
     fn test_concurrent_safety() {
         use std::thread;
@@ -450,6 +764,7 @@ mod tests {
         assert!(result.success);
         assert!(!result.data.is_null());
         assert_eq!(result.error_code, 0);
+        assert!(result.detail.is_null());
 
         free_result(result);
 
@@ -458,7 +773,95 @@ mod tests {
         assert!(!result_error.success);
         assert!(!result_error.data.is_null()); // Should contain error message
         assert_eq!(result_error.error_code, 1);
+        assert!(result_error.detail.is_null()); // NullPointer carries no detail
 
         free_result(result_error);
     }
+
+    #[test]
+    fn test_result_detail_carries_failure_reason() {
+        let long_message = CString::new("a".repeat(1001)).unwrap();
+        let result = lindos_process_message_safe(long_message.as_ptr());
+
+        assert!(!result.success);
+        assert_eq!(result.error_code, 4);
+        assert!(!result.detail.is_null());
+
+        let detail = unsafe { CStr::from_ptr(result.detail).to_str().unwrap() };
+        assert!(detail.contains("Message too long"));
+        assert!(detail.contains("1000"));
+
+        free_result(result);
+    }
+
+    #[test]
+    fn test_max_message_length() {
+        assert_eq!(lindos_max_message_length(), MAX_MESSAGE_LENGTH);
+    }
+
+    #[test]
+    fn test_process_batch() {
+        let messages: Vec<CString> = vec![
+            CString::new("hello").unwrap(),
+            CString::new("").unwrap(),
+            CString::new("a".repeat(1001)).unwrap(),
+        ];
+        let message_ptrs: Vec<*const c_char> = messages.iter().map(|m| m.as_ptr()).collect();
+
+        let results_ptr =
+            unsafe { lindos_process_batch(message_ptrs.as_ptr(), message_ptrs.len()) };
+        assert!(!results_ptr.is_null());
+
+        let results = unsafe { std::slice::from_raw_parts(results_ptr, message_ptrs.len()) };
+
+        assert!(results[0].success);
+        let reply = unsafe { CStr::from_ptr(results[0].data).to_str().unwrap() };
+        assert_eq!(reply, "You said: hello");
+
+        assert!(results[1].success);
+        let greeting = unsafe { CStr::from_ptr(results[1].data).to_str().unwrap() };
+        assert_eq!(greeting, "Hello from Rust core!");
+
+        assert!(!results[2].success);
+        assert_eq!(results[2].error_code, 4);
+        assert!(!results[2].detail.is_null());
+
+        unsafe { lindos_batch_free(results_ptr, message_ptrs.len()) };
+    }
+
+    #[test]
+    fn test_process_batch_empty() {
+        let results_ptr = unsafe { lindos_process_batch(std::ptr::null(), 0) };
+        unsafe { lindos_batch_free(results_ptr, 0) };
+    }
+
+    #[test]
+    fn test_last_error_cleared_on_success() {
+        lindos_clear_last_error();
+
+        let test_str = CString::new("hello").unwrap();
+        let result = lindos_process_message_safe(test_str.as_ptr());
+        free_result(result);
+
+        assert_eq!(lindos_last_error_code(), 0);
+        assert!(lindos_last_error().is_null());
+    }
+
+    #[test]
+    fn test_last_error_records_detail() {
+        let long_message = CString::new("a".repeat(1001)).unwrap();
+        let result = lindos_process_message_safe(long_message.as_ptr());
+        free_result(result);
+
+        assert_eq!(lindos_last_error_code(), 4);
+
+        let detail_ptr = lindos_last_error();
+        assert!(!detail_ptr.is_null());
+        let detail = unsafe { CStr::from_ptr(detail_ptr).to_str().unwrap() };
+        assert!(detail.contains("Message too long"));
+        free_string(detail_ptr);
+
+        lindos_clear_last_error();
+        assert_eq!(lindos_last_error_code(), 0);
+    }
 }